@@ -0,0 +1,223 @@
+/// Module containing delay-line based filters.
+use crate::core::generator::BufferWriter;
+
+/// A fractional-delay line backed by a circular buffer.
+///
+/// Integer-sample reads are exact; reads at a fractional position are reconstructed with 4-point
+/// cubic (Hermite) interpolation between the surrounding samples, rather than snapping to the
+/// nearest integer delay.
+pub struct DelayLine {
+    buffer: Vec<f32>,
+    write_index: usize,
+}
+
+impl DelayLine {
+    /// Creates a new `DelayLine` able to hold delays up to `max_delay` samples.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_delay` - The longest delay, in samples, that will ever be read from this line.
+    ///
+    /// # Returns
+    ///
+    /// A new `DelayLine`, initialized to silence.
+    pub fn new(max_delay: usize) -> DelayLine {
+        // A few extra slots of headroom so the 4-point interpolation window around the oldest
+        // readable sample never wraps into not-yet-written history.
+        DelayLine {
+            buffer: vec![0.0; max_delay + 4],
+            write_index: 0,
+        }
+    }
+
+    /// Writes the next input sample into the line, advancing the write position.
+    ///
+    /// # Arguments
+    ///
+    /// * `sample` - The sample to write.
+    pub fn write(&mut self, sample: f32) {
+        self.buffer[self.write_index] = sample;
+        self.write_index = (self.write_index + 1) % self.buffer.len();
+    }
+
+    /// Reads the line `delay` samples behind the write position, interpolating fractional
+    /// delays with a 4-point cubic (Hermite) interpolator.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - The delay, in samples, which need not be an integer. Must be at least `2.0`.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated sample at `delay` samples ago.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delay < 2.0`. The stencil's farthest-forward tap sits one sample ahead of the
+    /// read position, so anything shorter would read the slot this step is about to overwrite
+    /// rather than one it has actually written.
+    ///
+    /// ```should_panic
+    /// use signal::core::filters::DelayLine;
+    ///
+    /// let line = DelayLine::new(4);
+    /// line.read(1.9);
+    /// ```
+    pub fn read(&self, delay: f32) -> f32 {
+        assert!(
+            delay >= 2.0,
+            "DelayLine::read: delay must be at least 2.0 to keep the interpolation stencil \
+             causal, got {delay}"
+        );
+
+        let n = self.buffer.len() as isize;
+        let p = self.write_index as f32 - delay;
+        let i0 = p.floor();
+        let f = p - i0;
+
+        let at = |offset: isize| -> f32 {
+            let j = (i0 as isize + offset).rem_euclid(n) as usize;
+            self.buffer[j]
+        };
+
+        let y_m1 = at(-1);
+        let y0 = at(0);
+        let y1 = at(1);
+        let y2 = at(2);
+
+        let c0 = y0;
+        let c1 = 0.5 * (y1 - y_m1);
+        let c2 = y_m1 - 2.5 * y0 + 2.0 * y1 - 0.5 * y2;
+        let c3 = 0.5 * (y2 - y_m1) + 1.5 * (y0 - y1);
+
+        ((c3 * f + c2) * f + c1) * f + c0
+    }
+}
+
+/// A feedback comb filter: `y[n] = x[n] + feedback * y[n - delay]`.
+///
+/// # Example
+///
+/// ```
+/// use signal::core::filters::CombFilter;
+/// use signal::core::generator::{BufferWriter, Impulse};
+///
+/// let mut filter = CombFilter::new(Impulse::new(), 2.0, 0.5);
+/// let mut buffer = vec![0.0; 5];
+/// filter.write_buffer(buffer.as_mut_slice());
+///
+/// assert_eq!(buffer, vec![1.0, 0.0, 0.5, 0.0, 0.25]);
+/// ```
+///
+/// Fractional delays above the minimum work the same way, reading an interpolated historical
+/// sample instead of snapping to an integer one:
+///
+/// ```
+/// use assert_approx_eq::assert_approx_eq;
+/// use signal::core::filters::CombFilter;
+/// use signal::core::generator::{BufferWriter, Impulse};
+///
+/// let mut filter = CombFilter::new(Impulse::new(), 2.3, 0.5);
+/// let mut buffer = vec![0.0; 5];
+/// filter.write_buffer(buffer.as_mut_slice());
+///
+/// assert_approx_eq!(buffer[0], 1.0, 1e-3f32);
+/// assert_approx_eq!(buffer[2], 0.4091, 1e-3f32);
+/// ```
+pub struct CombFilter<I> {
+    source: I,
+    delay_line: DelayLine,
+    delay: f32,
+    feedback: f32,
+}
+
+impl<I> CombFilter<I> {
+    /// Creates a new `CombFilter` reading from `source` with the given `delay` (in samples) and
+    /// `feedback` gain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delay < 2.0`; see [`DelayLine::read`] for why the interpolation stencil needs
+    /// that much headroom to stay causal.
+    pub fn new(source: I, delay: f32, feedback: f32) -> CombFilter<I> {
+        assert!(delay >= 2.0, "CombFilter::new: delay must be at least 2.0, got {delay}");
+
+        CombFilter {
+            source,
+            delay_line: DelayLine::new(delay.ceil() as usize),
+            delay,
+            feedback,
+        }
+    }
+}
+
+impl<I: Iterator<Item = f32>> Iterator for CombFilter<I> {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let x = self.source.next()?;
+        let y = x + self.feedback * self.delay_line.read(self.delay);
+        self.delay_line.write(y);
+        Some(y)
+    }
+}
+
+impl<I: Iterator<Item = f32>> BufferWriter for CombFilter<I> {}
+
+/// A Schroeder all-pass filter: `y[n] = -feedback * x[n] + x[n - delay] + feedback * y[n - delay]`.
+///
+/// Built on the same [`DelayLine`] as [`CombFilter`], storing `x[n] + feedback * y[n]` so both
+/// delayed terms fall out of a single read.
+///
+/// # Example
+///
+/// ```
+/// use signal::core::filters::AllPassFilter;
+/// use signal::core::generator::{BufferWriter, Impulse};
+///
+/// let mut filter = AllPassFilter::new(Impulse::new(), 2.0, 0.5);
+/// let mut buffer = vec![0.0; 3];
+/// filter.write_buffer(buffer.as_mut_slice());
+///
+/// assert_eq!(buffer, vec![-0.5, 0.0, 0.75]);
+/// ```
+pub struct AllPassFilter<I> {
+    source: I,
+    delay_line: DelayLine,
+    delay: f32,
+    feedback: f32,
+}
+
+impl<I> AllPassFilter<I> {
+    /// Creates a new `AllPassFilter` reading from `source` with the given `delay` (in samples)
+    /// and `feedback` gain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delay < 2.0`; see [`DelayLine::read`] for why the interpolation stencil needs
+    /// that much headroom to stay causal.
+    pub fn new(source: I, delay: f32, feedback: f32) -> AllPassFilter<I> {
+        assert!(delay >= 2.0, "AllPassFilter::new: delay must be at least 2.0, got {delay}");
+
+        AllPassFilter {
+            source,
+            delay_line: DelayLine::new(delay.ceil() as usize),
+            delay,
+            feedback,
+        }
+    }
+}
+
+impl<I: Iterator<Item = f32>> Iterator for AllPassFilter<I> {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let x = self.source.next()?;
+        let delayed = self.delay_line.read(self.delay);
+        let y = -self.feedback * x + delayed;
+        self.delay_line.write(x + self.feedback * y);
+        Some(y)
+    }
+}
+
+impl<I: Iterator<Item = f32>> BufferWriter for AllPassFilter<I> {}
@@ -1,3 +1,60 @@
+use std::sync::OnceLock;
+
+/// Number of intervals in the cosine wavetable. The table itself holds `WAVETABLE_SIZE + 1`
+/// entries so the last entry closes the cycle (`tab[WAVETABLE_SIZE] == tab[0]`), which keeps the
+/// interpolation in [`fast_cos`] branch-free at the wraparound point.
+const WAVETABLE_SIZE: usize = 512;
+
+/// `1 / TAU`, used to turn a radian argument into a fraction of a full cycle before scaling it
+/// into a table index.
+const PHASE_SCALE: f32 = 1.0 / std::f32::consts::TAU;
+
+/// Returns the precomputed cosine table, building it on first use.
+fn cos_table() -> &'static [f32; WAVETABLE_SIZE + 1] {
+    static TABLE: OnceLock<[f32; WAVETABLE_SIZE + 1]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = [0.0; WAVETABLE_SIZE + 1];
+        for (i, entry) in table.iter_mut().enumerate() {
+            *entry = (i as f32 * std::f32::consts::TAU / WAVETABLE_SIZE as f32).cos();
+        }
+        table
+    })
+}
+
+/// Fast cosine approximation backed by a wavetable with linear interpolation between samples.
+///
+/// Accurate to within ~1e-3 of `f32::cos`, at a fraction of the cost of a per-sample trig call.
+///
+/// # Example
+///
+/// ```
+/// use assert_approx_eq::assert_approx_eq;
+/// use signal::core::generator::fast_cos;
+///
+/// assert_approx_eq!(fast_cos(0.0), 1.0, 1e-3f32);
+/// ```
+pub fn fast_cos(x: f32) -> f32 {
+    let table = cos_table();
+    let idx = x.abs() * PHASE_SCALE * WAVETABLE_SIZE as f32;
+    let i = idx as usize % WAVETABLE_SIZE;
+    let f = idx.fract();
+    table[i] + f * (table[i + 1] - table[i])
+}
+
+/// Fast sine approximation, derived from [`fast_cos`] via `sin(x) = cos(x - pi/2)`.
+///
+/// # Example
+///
+/// ```
+/// use assert_approx_eq::assert_approx_eq;
+/// use signal::core::generator::fast_sin;
+///
+/// assert_approx_eq!(fast_sin(0.0), 0.0, 1e-3f32);
+/// ```
+pub fn fast_sin(x: f32) -> f32 {
+    fast_cos(x - std::f32::consts::FRAC_PI_2)
+}
+
 /// A trait for writing to a buffer.
 pub trait BufferWriter: Iterator {
     /// Writes the next `n` samples to the buffer.
@@ -24,6 +81,216 @@ pub trait BufferWriter: Iterator {
     }
 }
 
+/// Errors that can occur while running a fallible DSP pipeline.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DspError {
+    /// A sample fell outside the range a stage is able to process, carrying the offending value.
+    OutOfRange(f32),
+    /// A processing failure that doesn't fit `OutOfRange`, carrying a human-readable reason.
+    Processing(String),
+}
+
+impl std::fmt::Display for DspError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DspError::OutOfRange(x) => write!(f, "sample {x} is out of range"),
+            DspError::Processing(reason) => write!(f, "processing failed: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for DspError {}
+
+/// A trait for writing to a buffer from a fallible processing pipeline, mirroring
+/// [`BufferWriter`] for iterators whose `Item` is `Result<f32, DspError>`.
+pub trait TryBufferWriter: Iterator<Item = Result<f32, DspError>> {
+    /// Writes the next `n` samples to the buffer, aborting on the first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The buffer to write to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use signal::core::generator::{Clipper, DspError, IntoFallible, TryBufferWriter};
+    ///
+    /// let samples = vec![0.2, f32::NAN, 1.0].into_iter().fallible();
+    /// let mut clipper = Clipper::new(samples, 1.0);
+    /// let mut buffer = vec![0.0; 3];
+    ///
+    /// assert!(matches!(
+    ///     clipper.try_write_buffer(buffer.as_mut_slice()),
+    ///     Err(DspError::OutOfRange(_))
+    /// ));
+    /// ```
+    fn try_write_buffer(&mut self, buffer: &mut [f32]) -> Result<(), DspError> {
+        for e in buffer.iter_mut() {
+            *e = match self.next() {
+                Some(Ok(x)) => x,
+                Some(Err(err)) => return Err(err),
+                None => return Err(DspError::Processing("source exhausted".to_string())),
+            };
+        }
+        Ok(())
+    }
+}
+
+/// Extension trait that lifts an infallible `f32` generator into the `Result`-based pipeline so
+/// it can feed [`AmDemodulator`], [`Clipper`], or [`Normalizer`].
+pub trait IntoFallible: Iterator<Item = f32> + Sized {
+    /// Wraps `self` so every sample is reported as `Ok`.
+    fn fallible(self) -> Fallible<Self> {
+        Fallible { source: self }
+    }
+}
+
+impl<I: Iterator<Item = f32>> IntoFallible for I {}
+
+/// Wraps an infallible `f32` iterator so it produces `Result<f32, DspError>`, always `Ok`.
+pub struct Fallible<I> {
+    source: I,
+}
+
+impl<I: Iterator<Item = f32>> Iterator for Fallible<I> {
+    type Item = Result<f32, DspError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.source.next().map(Ok)
+    }
+}
+
+impl<I: Iterator<Item = f32>> TryBufferWriter for Fallible<I> {}
+
+/// Demodulates an amplitude-modulated source by taking the envelope of each sample,
+/// `sqrt(x * x)`, propagating any upstream error unchanged.
+///
+/// # Example
+///
+/// ```
+/// use signal::core::generator::{AmDemodulator, IntoFallible};
+///
+/// let samples = vec![-1.0, 0.5, -0.25].into_iter().fallible();
+/// let demodulated: Result<Vec<f32>, _> = AmDemodulator::new(samples).collect();
+///
+/// assert_eq!(demodulated, Ok(vec![1.0, 0.5, 0.25]));
+/// ```
+pub struct AmDemodulator<I> {
+    source: I,
+}
+
+impl<I> AmDemodulator<I> {
+    /// Creates a new `AmDemodulator` reading samples from `source`.
+    pub fn new(source: I) -> AmDemodulator<I> {
+        AmDemodulator { source }
+    }
+}
+
+impl<I> Iterator for AmDemodulator<I>
+where
+    I: Iterator<Item = Result<f32, DspError>>,
+{
+    type Item = Result<f32, DspError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.source.next() {
+            Some(Ok(x)) => Some(Ok((x * x).sqrt())),
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Result<f32, DspError>>> TryBufferWriter for AmDemodulator<I> {}
+
+/// Clips a source to `[-limit, limit]`, reporting a [`DspError::OutOfRange`] for any non-finite
+/// sample (e.g. `NaN` or `inf` produced upstream) instead of silently clamping it away.
+///
+/// # Example
+///
+/// ```
+/// use signal::core::generator::{Clipper, IntoFallible};
+///
+/// let samples = vec![0.2, -2.0, 3.0].into_iter().fallible();
+/// let clipped: Result<Vec<f32>, _> = Clipper::new(samples, 1.0).collect();
+///
+/// assert_eq!(clipped, Ok(vec![0.2, -1.0, 1.0]));
+/// ```
+pub struct Clipper<I> {
+    source: I,
+    limit: f32,
+}
+
+impl<I> Clipper<I> {
+    /// Creates a new `Clipper` that clamps samples from `source` to `[-limit, limit]`.
+    pub fn new(source: I, limit: f32) -> Clipper<I> {
+        Clipper { source, limit }
+    }
+}
+
+impl<I> Iterator for Clipper<I>
+where
+    I: Iterator<Item = Result<f32, DspError>>,
+{
+    type Item = Result<f32, DspError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.source.next() {
+            Some(Ok(x)) if x.is_finite() => Some(Ok(x.clamp(-self.limit, self.limit))),
+            Some(Ok(x)) => Some(Err(DspError::OutOfRange(x))),
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Result<f32, DspError>>> TryBufferWriter for Clipper<I> {}
+
+/// Normalizes a source by a fixed, known peak amplitude, reporting a
+/// [`DspError::Processing`] error rather than dividing by zero.
+///
+/// # Example
+///
+/// ```
+/// use signal::core::generator::{Normalizer, IntoFallible};
+///
+/// let samples = vec![1.0, -2.0, 4.0].into_iter().fallible();
+/// let normalized: Result<Vec<f32>, _> = Normalizer::new(samples, 4.0).collect();
+///
+/// assert_eq!(normalized, Ok(vec![0.25, -0.5, 1.0]));
+/// ```
+pub struct Normalizer<I> {
+    source: I,
+    peak: f32,
+}
+
+impl<I> Normalizer<I> {
+    /// Creates a new `Normalizer` that divides samples from `source` by `peak`.
+    pub fn new(source: I, peak: f32) -> Normalizer<I> {
+        Normalizer { source, peak }
+    }
+}
+
+impl<I> Iterator for Normalizer<I>
+where
+    I: Iterator<Item = Result<f32, DspError>>,
+{
+    type Item = Result<f32, DspError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.source.next() {
+            Some(Ok(_)) if self.peak == 0.0 => Some(Err(DspError::Processing(
+                "cannot normalize by a zero peak".to_string(),
+            ))),
+            Some(Ok(x)) => Some(Ok(x / self.peak)),
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Result<f32, DspError>>> TryBufferWriter for Normalizer<I> {}
+
 /// A struct representing an impulse generator.
 ///
 /// # Example
@@ -155,7 +422,7 @@ impl Iterator for Step {
 /// assert_approx_eq!(buffer[3], -1.0, 1e-5f32);
 /// ```
 pub struct Sine {
-    step_pos: usize,
+    phase: f32,
     freq: f32,
     sample_rate: usize,
 }
@@ -173,7 +440,7 @@ impl Sine {
     /// A new `Sine` generator.
     pub fn new(freq: f32, sample_rate: usize) -> Sine {
         Sine {
-            step_pos: 0,
+            phase: 0.0,
             freq,
             sample_rate,
         }
@@ -187,13 +454,22 @@ impl Iterator for Sine {
 
     /// Generates the next sample of the sine wave.
     ///
+    /// Drives a phase accumulator in `[0, 1)` through [`fast_sin`] rather than calling
+    /// `f32::sin()` every sample, which also avoids the unbounded growth (and eventual loss of
+    /// precision) of a raw sample counter.
+    ///
     /// # Returns
     ///
     /// The next sample of the sine wave as an `Option<f32>`.
     fn next(&mut self) -> Option<f32> {
-        let t = self.step_pos as f32 / self.sample_rate as f32;
-        self.step_pos += 1;
-        Some((t * self.freq * 2.0 * std::f32::consts::PI).sin())
+        let sample = fast_sin(self.phase * std::f32::consts::TAU);
+
+        self.phase += self.freq / self.sample_rate as f32;
+        if self.phase >= 1.0 {
+            self.phase -= 1.0;
+        }
+
+        Some(sample)
     }
 }
 
@@ -333,3 +609,293 @@ impl Iterator for Square {
         }
     }
 }
+
+/// The waveform shape a [`Periodic`] oscillator maps its phase through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Waveform {
+    /// `sin(2 * pi * phase)`, via [`fast_sin`].
+    Sine,
+    /// A ramp from -1 to 1 across the cycle.
+    Sawtooth,
+    /// -1 for the first half of the cycle, 1 for the second.
+    Square,
+    /// A ramp from -1 to 1 across the first half-cycle and back down across the second.
+    Triangle,
+}
+
+impl Waveform {
+    /// Maps a phase in `[0, 1)` to this waveform's canonical `[-1, 1]` value.
+    fn sample(self, phase: f32) -> f32 {
+        match self {
+            Waveform::Sine => fast_sin(phase * std::f32::consts::TAU),
+            Waveform::Sawtooth => 2.0 * phase - 1.0,
+            Waveform::Square => {
+                if phase < 0.5 {
+                    -1.0
+                } else {
+                    1.0
+                }
+            }
+            Waveform::Triangle => {
+                let folded = if phase < 0.5 { phase } else { 1.0 - phase };
+                4.0 * folded - 1.0
+            }
+        }
+    }
+}
+
+/// A general periodic oscillator parameterized by amplitude, frequency, initial phase, and DC
+/// offset, for any of the [`Waveform`] shapes.
+///
+/// Unlike [`Sine`]/[`Sawtooth`]/[`Square`], which hardcode unit amplitude and zero phase,
+/// `Periodic` drives an integer sample counter `k` through a precomputed per-sample
+/// `step = freq / sample_rate`: the instantaneous phase is `k * step + phase0` reduced modulo
+/// 1.0, which the waveform maps to a value before it's scaled by `amplitude` and shifted by
+/// `offset`. Using an integer `k` with a modulus avoids the unbounded growth (and eventual
+/// precision loss) of accumulating `step` directly every sample.
+///
+/// # Example
+///
+/// ```
+/// use assert_approx_eq::assert_approx_eq;
+/// use signal::core::generator::{BufferWriter, Periodic, Waveform};
+///
+/// let mut signal = Periodic::new(Waveform::Sine, 2.0, 2.0, 8, 0.0, 1.0);
+/// let mut buffer = vec![0.0; 4];
+/// signal.write_buffer(buffer.as_mut_slice());
+///
+/// assert_approx_eq!(buffer[0], 1.0, 1e-3f32);
+/// assert_approx_eq!(buffer[1], 3.0, 1e-3f32);
+/// assert_approx_eq!(buffer[2], 1.0, 1e-3f32);
+/// assert_approx_eq!(buffer[3], -1.0, 1e-3f32);
+/// ```
+pub struct Periodic {
+    k: usize,
+    step: f32,
+    phase0: f32,
+    amplitude: f32,
+    offset: f32,
+    waveform: Waveform,
+}
+
+impl Periodic {
+    /// Creates a new `Periodic` generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `waveform` - The waveform shape to sample.
+    /// * `amplitude` - The peak amplitude of the waveform before the DC offset is applied.
+    /// * `freq` - The frequency of the waveform.
+    /// * `sample_rate` - The sample rate of the generator.
+    /// * `phase0` - The initial phase, as a fraction of a cycle.
+    /// * `offset` - The DC offset added after scaling by `amplitude`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Periodic` generator.
+    pub fn new(
+        waveform: Waveform,
+        amplitude: f32,
+        freq: f32,
+        sample_rate: usize,
+        phase0: f32,
+        offset: f32,
+    ) -> Periodic {
+        Periodic {
+            k: 0,
+            step: freq / sample_rate as f32,
+            phase0,
+            amplitude,
+            offset,
+            waveform,
+        }
+    }
+}
+
+impl BufferWriter for Periodic {}
+
+impl Iterator for Periodic {
+    type Item = f32;
+
+    /// Generates the next sample of the periodic waveform.
+    ///
+    /// # Returns
+    ///
+    /// The next sample of the waveform as an `Option<f32>`.
+    fn next(&mut self) -> Option<f32> {
+        let phase = (self.k as f32 * self.step + self.phase0).rem_euclid(1.0);
+        self.k += 1;
+        Some(self.amplitude * self.waveform.sample(phase) + self.offset)
+    }
+}
+
+/// Returns `len` linearly spaced values from `start` to `stop`, inclusive of both endpoints.
+///
+/// # Example
+///
+/// ```
+/// use signal::core::generator::lin_spaced;
+///
+/// assert_eq!(lin_spaced(4, 1.0, 4.0), vec![1.0, 2.0, 3.0, 4.0]);
+/// ```
+pub fn lin_spaced(len: usize, start: f32, stop: f32) -> Vec<f32> {
+    if len <= 1 {
+        return vec![start; len];
+    }
+
+    let step = (stop - start) / (len - 1) as f32;
+    (0..len).map(|i| start + i as f32 * step).collect()
+}
+
+/// Returns `len` base-10 log-spaced values from `10^start_exp` to `10^stop_exp`, inclusive of
+/// both endpoints. The last element is pinned exactly to `10^stop_exp` rather than left to
+/// accumulate rounding drift through the exponent interpolation.
+///
+/// # Example
+///
+/// ```
+/// use assert_approx_eq::assert_approx_eq;
+/// use signal::core::generator::log_spaced;
+///
+/// let values = log_spaced(4, 0.0, 3.0);
+/// assert_approx_eq!(values[0], 1.0, 1e-3f32);
+/// assert_approx_eq!(values[3], 1000.0, 1e-3f32);
+/// ```
+///
+/// A `len` of `1`, like [`lin_spaced`], returns the start-derived value rather than the stop:
+///
+/// ```
+/// use assert_approx_eq::assert_approx_eq;
+/// use signal::core::generator::log_spaced;
+///
+/// assert_approx_eq!(log_spaced(1, 0.0, 3.0)[0], 1.0, 1e-3f32);
+/// ```
+pub fn log_spaced(len: usize, start_exp: f32, stop_exp: f32) -> Vec<f32> {
+    let mut values: Vec<f32> = lin_spaced(len, start_exp, stop_exp)
+        .into_iter()
+        .map(|exp| 10f32.powf(exp))
+        .collect();
+
+    // Match `lin_spaced`: a single-element result is the start value, not the stop value.
+    if len > 1 {
+        if let Some(last) = values.last_mut() {
+            *last = 10f32.powf(stop_exp);
+        }
+    }
+
+    values
+}
+
+/// How the instantaneous frequency of a [`Chirp`] is spaced between its start and end frequency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SweepScale {
+    /// Frequency steps are evenly spaced in Hz, via [`lin_spaced`].
+    Linear,
+    /// Frequency steps are evenly spaced in octaves/decades, via [`log_spaced`].
+    Logarithmic,
+}
+
+/// A swept-sine (chirp) generator: the instantaneous frequency moves from `f_start` to `f_end`
+/// across the buffer, linearly or logarithmically, by integrating the per-sample frequency into
+/// a phase accumulator and emitting `sin(phase)` via [`fast_sin`].
+///
+/// Unlike the fixed-frequency generators above, `Chirp` is finite: it yields exactly `len`
+/// samples (one per precomputed frequency step) and then returns `None`.
+///
+/// # Example
+///
+/// ```
+/// use assert_approx_eq::assert_approx_eq;
+/// use signal::core::generator::{BufferWriter, Chirp, SweepScale};
+///
+/// let mut signal = Chirp::new(1.0, 4.0, 4, 8, SweepScale::Linear);
+/// let mut buffer = vec![0.0; 4];
+/// signal.write_buffer(buffer.as_mut_slice());
+///
+/// assert_approx_eq!(buffer[0], 0.0, 1e-3f32);
+/// assert_approx_eq!(buffer[3], -1.0, 1e-3f32);
+/// ```
+pub struct Chirp {
+    freqs: Vec<f32>,
+    sample_rate: usize,
+    phase: f32,
+    pos: usize,
+}
+
+impl Chirp {
+    /// Creates a new `Chirp` sweeping from `f_start` to `f_end` across `len` samples.
+    ///
+    /// # Arguments
+    ///
+    /// * `f_start` - The instantaneous frequency at the first sample.
+    /// * `f_end` - The instantaneous frequency at the last sample.
+    /// * `len` - How many samples the sweep spans.
+    /// * `sample_rate` - The sample rate of the generator.
+    /// * `scale` - Whether the frequency steps are linearly or logarithmically spaced.
+    ///
+    /// # Returns
+    ///
+    /// A new `Chirp` generator.
+    pub fn new(f_start: f32, f_end: f32, len: usize, sample_rate: usize, scale: SweepScale) -> Chirp {
+        let freqs = match scale {
+            SweepScale::Linear => lin_spaced(len, f_start, f_end),
+            SweepScale::Logarithmic => log_spaced(len, f_start.log10(), f_end.log10()),
+        };
+
+        Chirp {
+            freqs,
+            sample_rate,
+            phase: 0.0,
+            pos: 0,
+        }
+    }
+}
+
+impl BufferWriter for Chirp {
+    /// Writes up to `buffer.len()` samples, stopping early once the sweep's `len` samples are
+    /// exhausted instead of panicking like the default [`BufferWriter::write_buffer`] would.
+    /// Any trailing slots past the end of the sweep are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use signal::core::generator::{BufferWriter, Chirp, SweepScale};
+    ///
+    /// let mut signal = Chirp::new(1.0, 4.0, 4, 8, SweepScale::Linear);
+    /// let mut buffer = vec![0.0; 6];
+    /// signal.write_buffer(buffer.as_mut_slice());
+    ///
+    /// assert_eq!(&buffer[4..], &[0.0, 0.0]);
+    /// ```
+    fn write_buffer(&mut self, buffer: &mut [f32]) {
+        for e in buffer.iter_mut() {
+            match self.next() {
+                Some(sample) => *e = sample,
+                None => break,
+            }
+        }
+    }
+}
+
+impl Iterator for Chirp {
+    type Item = f32;
+
+    /// Generates the next sample of the sweep, or `None` once `len` samples have been emitted.
+    ///
+    /// # Returns
+    ///
+    /// The next sample of the sweep as an `Option<f32>`.
+    fn next(&mut self) -> Option<f32> {
+        let freq = *self.freqs.get(self.pos)?;
+        self.pos += 1;
+
+        let sample = fast_sin(self.phase * std::f32::consts::TAU);
+
+        self.phase += freq / self.sample_rate as f32;
+        if self.phase >= 1.0 {
+            self.phase -= 1.0;
+        }
+
+        Some(sample)
+    }
+}
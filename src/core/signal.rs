@@ -1,6 +1,190 @@
 /// Module containing the `Signal` struct.
 use std::ops::{Add, Mul};
 
+/// Output length, in samples, above which [`Signal::mul`] switches from the naive O(n*m)
+/// convolution loop to the O(N log N) FFT path. Below this the naive loop wins on overhead alone.
+const FFT_THRESHOLD: usize = 256;
+
+/// Types whose `Signal` multiplication can provide a convolution strategy.
+///
+/// The default implementation is the naive double loop; concrete types (see the `f32` impl)
+/// can override it with a faster strategy for the same `Signal::mul` call site. `Signal<T>: Mul`
+/// requires `T: Convolvable`, so any numeric type used with `Signal::mul` needs an impl here —
+/// the per-type impls below cover the crate's plausible PCM/sample types out of the box.
+pub trait Convolvable: Mul<Output = Self> + Add<Output = Self> + Default + Copy {
+    /// Convolves `a` with `b`, producing `a.len() + b.len() - 1` samples.
+    fn convolve(a: &[Self], b: &[Self]) -> Vec<Self> {
+        naive_convolve(a, b)
+    }
+}
+
+impl Convolvable for i8 {}
+impl Convolvable for i16 {}
+impl Convolvable for i32 {}
+impl Convolvable for i64 {}
+impl Convolvable for u8 {}
+impl Convolvable for u16 {}
+impl Convolvable for u32 {}
+impl Convolvable for u64 {}
+impl Convolvable for f64 {}
+
+impl Convolvable for f32 {
+    /// Convolves `a` with `b`, using the FFT for long signals and the naive loop for short ones.
+    fn convolve(a: &[Self], b: &[Self]) -> Vec<Self> {
+        if a.len() + b.len() - 1 > FFT_THRESHOLD {
+            convolve_fft(a, b)
+        } else {
+            naive_convolve(a, b)
+        }
+    }
+}
+
+/// The O(n*m) convolution loop, used directly for short signals and as the fallback for types
+/// with no faster [`Convolvable`] strategy.
+fn naive_convolve<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Default + Copy,
+{
+    let len = a.len() + b.len() - 1;
+    let mut buffer = vec![T::default(); len];
+
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            buffer[i + j] = buffer[i + j] + x * y;
+        }
+    }
+
+    buffer
+}
+
+/// A minimal complex number, just enough to drive the FFT convolution below.
+#[derive(Clone, Copy, Debug, Default)]
+struct Complex32 {
+    re: f32,
+    im: f32,
+}
+
+impl Complex32 {
+    fn new(re: f32, im: f32) -> Complex32 {
+        Complex32 { re, im }
+    }
+}
+
+impl Add for Complex32 {
+    type Output = Complex32;
+    fn add(self, rhs: Complex32) -> Complex32 {
+        Complex32::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl std::ops::Sub for Complex32 {
+    type Output = Complex32;
+    fn sub(self, rhs: Complex32) -> Complex32 {
+        Complex32::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex32 {
+    type Output = Complex32;
+    fn mul(self, rhs: Complex32) -> Complex32 {
+        Complex32::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+/// Returns the smallest power of two that is `>= n`.
+fn next_pow2(n: usize) -> usize {
+    let mut size = 1;
+    while size < n {
+        size <<= 1;
+    }
+    size
+}
+
+/// In-place iterative Cooley-Tukey radix-2 FFT. `data.len()` must be a power of two.
+/// Set `invert` to compute the inverse transform, including the `1/N` scale.
+fn fft(data: &mut [Complex32], invert: bool) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = if invert { 1.0 } else { -1.0 } * std::f32::consts::TAU / len as f32;
+        let w_len = Complex32::new(angle.cos(), angle.sin());
+
+        let mut start = 0;
+        while start < n {
+            let mut w = Complex32::new(1.0, 0.0);
+            for k in 0..len / 2 {
+                let u = data[start + k];
+                let v = data[start + k + len / 2] * w;
+                data[start + k] = u + v;
+                data[start + k + len / 2] = u - v;
+                w = w * w_len;
+            }
+            start += len;
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        for sample in data.iter_mut() {
+            sample.re /= n as f32;
+            sample.im /= n as f32;
+        }
+    }
+}
+
+/// Convolves `a` with `b` in the frequency domain: zero-pad both to the next power of two
+/// `>= a.len() + b.len() - 1`, forward-FFT each, multiply the spectra pointwise, inverse-FFT, and
+/// take the real part truncated to `a.len() + b.len() - 1` samples.
+fn convolve_fft(a: &[f32], b: &[f32]) -> Vec<f32> {
+    let out_len = a.len() + b.len() - 1;
+    let padded_len = next_pow2(out_len);
+
+    let mut fa: Vec<Complex32> = a
+        .iter()
+        .map(|&x| Complex32::new(x, 0.0))
+        .chain(std::iter::repeat(Complex32::default()))
+        .take(padded_len)
+        .collect();
+    let mut fb: Vec<Complex32> = b
+        .iter()
+        .map(|&x| Complex32::new(x, 0.0))
+        .chain(std::iter::repeat(Complex32::default()))
+        .take(padded_len)
+        .collect();
+
+    fft(&mut fa, false);
+    fft(&mut fb, false);
+
+    for (x, y) in fa.iter_mut().zip(fb.iter()) {
+        *x = *x * *y;
+    }
+
+    fft(&mut fa, true);
+
+    fa.iter().take(out_len).map(|c| c.re).collect()
+}
+
 /// A struct representing a signal.
 pub struct Signal<T> {
     data: Vec<T>,
@@ -89,6 +273,32 @@ impl<T> Signal<T> {
     }
 }
 
+impl Signal<f32> {
+    /// Convolves this signal with `other` entirely in the frequency domain: zero-pads both to
+    /// the next power of two `>= n + m - 1`, forward-FFTs each, multiplies the complex spectra
+    /// pointwise, inverse-FFTs, and takes the real part truncated to `n + m - 1` samples.
+    ///
+    /// This is an O(N log N) alternative to the naive O(n*m) loop in [`Mul`], which
+    /// [`Signal::mul`] already switches to for long signals; call it directly to force the
+    /// FFT path regardless of length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use signal::core::signal::Signal;
+    ///
+    /// let a = Signal::from_buffer(vec![1.0, 2.0, 3.0]);
+    /// let b = Signal::from_buffer(vec![4.0, 5.0]);
+    ///
+    /// let c = a.convolve_fft(&b);
+    ///
+    /// assert_eq!(c.as_slice().len(), 4);
+    /// ```
+    pub fn convolve_fft(&self, other: &Signal<f32>) -> Signal<f32> {
+        Signal::from_buffer(convolve_fft(&self.data, &other.data))
+    }
+}
+
 impl<T> Add for Signal<T>
 where
     T: Add<Output = T> + Default + Copy,
@@ -139,11 +349,13 @@ where
 
 impl<T> Mul for Signal<T>
 where
-    T: Mul<Output = T> + Add<Output = T> + Default + Copy,
+    T: Convolvable,
 {
     type Output = Self;
 
-    /// Multiplies two signals together. This is done using the convolution theorem.
+    /// Multiplies two signals together. This is done using the convolution theorem: types with
+    /// a fast [`Convolvable`] strategy (currently `f32`) use it once the output is long enough
+    /// for the overhead to pay off, otherwise the naive loop runs directly.
     ///
     /// * `rhs` - The right hand side of the multiplication.
     ///
@@ -164,18 +376,6 @@ where
     /// assert_eq!(c.as_slice(), &[4, 13, 22, 15]);
     /// ```
     fn mul(self, rhs: Self) -> Self::Output {
-        let len = self.data.len() + rhs.data.len() - 1;
-        let mut buffer = vec![Default::default(); len];
-
-        for i in 0..self.data.len() {
-            for j in 0..rhs.data.len() {
-                let a = self.data[i];
-                let b = rhs.data[j];
-                let c = a * b;
-                buffer[i + j] = buffer[i + j] + c;
-            }
-        }
-
-        Signal::from_buffer(buffer)
+        Signal::from_buffer(T::convolve(&self.data, &rhs.data))
     }
 }
@@ -0,0 +1,175 @@
+/// Module containing a lock-free streaming bridge between generators and a live audio callback.
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A single-producer, single-writer-side circular buffer of raw (interleaved) `f32` samples.
+///
+/// Only the read/write indices are synchronized; the sample slots themselves are written and
+/// read without locking because the producer and the consumer never touch the same slot at the
+/// same time.
+struct RingBuffer {
+    data: Box<[UnsafeCell<f32>]>,
+    capacity: usize,
+    read_index: AtomicUsize,
+    write_index: AtomicUsize,
+}
+
+// Safety: `read_index`/`write_index` establish a happens-before relationship (via
+// Acquire/Release) between the producer and the consumer, so the raw sample slots they guard
+// can safely be handed across threads.
+unsafe impl Sync for RingBuffer {}
+
+impl RingBuffer {
+    fn new(capacity: usize) -> RingBuffer {
+        let data = (0..capacity)
+            .map(|_| UnsafeCell::new(0.0))
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+        RingBuffer {
+            data,
+            capacity,
+            read_index: AtomicUsize::new(0),
+            write_index: AtomicUsize::new(0),
+        }
+    }
+
+    fn free_space(&self) -> usize {
+        let r = self.read_index.load(Ordering::Acquire);
+        let w = self.write_index.load(Ordering::Acquire);
+        self.capacity - (w.wrapping_sub(r))
+    }
+
+    fn available(&self) -> usize {
+        self.capacity - self.free_space()
+    }
+
+    /// Writes `samples` into the buffer. The caller must have already checked `free_space()`.
+    fn push_slice(&self, samples: &[f32]) {
+        let w = self.write_index.load(Ordering::Relaxed);
+        for (i, &sample) in samples.iter().enumerate() {
+            let idx = (w + i) % self.capacity;
+            // Safety: this slot is `free_space()` slots ahead of the last read index, so the
+            // consumer cannot be reading it concurrently.
+            unsafe { *self.data[idx].get() = sample };
+        }
+        self.write_index.store(w + samples.len(), Ordering::Release);
+    }
+
+    /// Reads up to `out.len()` samples into `out`, returning how many were actually read.
+    fn pop_slice(&self, out: &mut [f32]) -> usize {
+        let r = self.read_index.load(Ordering::Relaxed);
+        let n = usize::min(out.len(), self.available());
+        for (i, slot) in out.iter_mut().take(n).enumerate() {
+            let idx = (r + i) % self.capacity;
+            // Safety: this slot is `available()` slots behind the write index, so the producer
+            // cannot be writing it concurrently.
+            *slot = unsafe { *self.data[idx].get() };
+        }
+        self.read_index.store(r + n, Ordering::Release);
+        n
+    }
+}
+
+/// A streaming bridge that lets a generator fill a lock-free ring buffer for a live audio
+/// callback to drain, instead of rendering a single fixed-size `Vec` snapshot.
+///
+/// The sink is configured for a fixed channel count `N`; [`space_available`](Self::space_available)
+/// and [`fill_with`](Self::fill_with) both operate in units of interleaved frames (one sample per
+/// channel), so a generator filling mono samples can never write a partial frame and desync a
+/// stereo (or wider) stream.
+///
+/// # Example
+///
+/// ```
+/// use signal::core::generator::{BufferWriter, Sine};
+/// use signal::core::sink::AudioSink;
+///
+/// let sink = AudioSink::new(8, 2);
+/// let mut generator = Sine::new(440.0, 44_100);
+///
+/// let space = sink.space_available();
+/// let mut chunk = vec![0.0; space];
+/// generator.write_buffer(chunk.as_mut_slice());
+/// assert!(sink.fill_with(&chunk));
+///
+/// assert_eq!(sink.space_available(), 0);
+/// ```
+pub struct AudioSink {
+    ring: RingBuffer,
+    channels: usize,
+}
+
+impl AudioSink {
+    /// Creates a new `AudioSink` holding up to `capacity_frames` interleaved frames across
+    /// `channels` channels.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity_frames` - How many frames (one sample per channel) the ring buffer can hold.
+    /// * `channels` - The number of interleaved channels the sink is configured for.
+    ///
+    /// # Returns
+    ///
+    /// A new, empty `AudioSink`.
+    pub fn new(capacity_frames: usize, channels: usize) -> AudioSink {
+        AudioSink {
+            ring: RingBuffer::new(capacity_frames * channels),
+            channels,
+        }
+    }
+
+    /// Returns how many frames are free for [`fill_with`](Self::fill_with) to write right now.
+    pub fn space_available(&self) -> usize {
+        self.ring.free_space() / self.channels
+    }
+
+    /// Writes `buffer`, one sample per frame, duplicated across every channel.
+    ///
+    /// Refuses to write at all (returning `false`) unless the ring buffer has at least
+    /// `buffer.len() * channels` free raw slots, so a stereo (or wider) stream can never be left
+    /// with a half-written frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The samples to write, one per frame.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the buffer was written, `false` if there wasn't enough free space.
+    pub fn fill_with(&self, buffer: &[f32]) -> bool {
+        if self.ring.free_space() < buffer.len() * self.channels {
+            return false;
+        }
+
+        let mut interleaved = Vec::with_capacity(buffer.len() * self.channels);
+        for &sample in buffer {
+            for _ in 0..self.channels {
+                interleaved.push(sample);
+            }
+        }
+        self.ring.push_slice(&interleaved);
+        true
+    }
+
+    /// Drains up to `out.len()` interleaved raw samples into `out`, the audio-callback side of
+    /// the bridge. Returns how many samples were actually read.
+    ///
+    /// Only ever reads whole frames: if `out.len()` isn't a multiple of `channels`, the trailing
+    /// partial frame's worth of slots is left untouched. Draining a non-multiple of `channels`
+    /// would otherwise leave the ring's read cursor mid-frame, so every later call would desync
+    /// by that same offset and [`space_available`](Self::space_available)/
+    /// [`fill_with`](Self::fill_with) would keep dividing by `channels` as if it were still
+    /// frame-aligned.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The buffer to read interleaved samples into.
+    ///
+    /// # Returns
+    ///
+    /// The number of samples actually read, which may be less than `out.len()`.
+    pub fn read_into(&self, out: &mut [f32]) -> usize {
+        let aligned_len = (out.len() / self.channels) * self.channels;
+        self.ring.pop_slice(&mut out[..aligned_len])
+    }
+}